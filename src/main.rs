@@ -1,13 +1,14 @@
 use axum::{
-    extract::DefaultBodyLimit,
+    extract::{DefaultBodyLimit, FromRef},
     http::{HeaderValue, Method, StatusCode},
     response::Html,
     routing::{delete, get},
     Router,
 };
 use serde::{Deserialize, Serialize};
-use sqlx::{postgres::PgPoolOptions, prelude::FromRow};
-use std::{net::SocketAddr, time::Duration};
+use sqlx::{postgres::PgPoolOptions, prelude::FromRow, PgPool};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use tokio::sync::mpsc;
 use tower_http::{cors::CorsLayer, limit::RequestBodyLimitLayer};
 use tower_http::{
     services::{ServeDir, ServeFile},
@@ -22,51 +23,538 @@ struct Podcast {
     title: String,
     url: String,
     created_at: Option<chrono::NaiveDateTime>,
+    valid_till: Option<chrono::NaiveDateTime>,
+    duration_secs: Option<f64>,
+    bitrate: Option<i64>,
+    sample_rate: Option<i32>,
+    codec: Option<String>,
+    content_type: Option<String>,
+    folder_id: Option<Uuid>,
+    size_bytes: Option<i64>,
+}
+
+/// A show: a named container for episodes that can itself be nested inside
+/// another folder via `parent_folder_id`.
+#[derive(Debug, Deserialize, Serialize, FromRow)]
+struct Folder {
+    id: Uuid,
+    name: String,
+    parent_folder_id: Option<Uuid>,
+    created_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Shared axum router state. `expiry_notify` wakes the background
+/// [`expiry::run_deleter`] task whenever an upload lands with a `valid_till`
+/// sooner than whatever it's currently sleeping on. `store` is the
+/// configured [`store::MediaStore`] backend audio files are read from and
+/// written to.
+#[derive(Clone)]
+struct AppState {
+    pool: PgPool,
+    expiry_notify: mpsc::Sender<()>,
+    store: Arc<dyn store::MediaStore>,
+}
+
+impl FromRef<AppState> for PgPool {
+    fn from_ref(state: &AppState) -> Self {
+        state.pool.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn store::MediaStore> {
+    fn from_ref(state: &AppState) -> Self {
+        state.store.clone()
+    }
+}
+
+/// Recovers the store key (content-addressed file name) from a podcast's
+/// `/audio/{key}` URL.
+fn key_from_url(url: &str) -> &str {
+    url.trim_start_matches("/audio/")
+}
+
+/// Whether any podcast row still points at `url`. Content-addressed uploads
+/// can share a single stored blob across rows, so deletion paths must check
+/// this before removing the underlying file.
+async fn url_still_referenced(pool: &PgPool, url: &str) -> Result<bool, sqlx::Error> {
+    Ok(
+        sqlx::query_scalar!("SELECT EXISTS(SELECT 1 FROM podcast WHERE url = $1)", url)
+            .fetch_one(pool)
+            .await?
+            .unwrap_or(false),
+    )
+}
+
+/// Default number of days an uploaded episode is kept before it's deleted,
+/// used when the request doesn't send a `keep_for` field.
+const DEFAULT_KEEP_FOR_DAYS: i64 = 30;
+
+/// Pluggable backend for audio blobs, decoupling the handlers from any one
+/// storage medium. Everything is addressed by `key`, which today is the
+/// content-addressed file name (`{sha256}.{ext}`) from
+/// `service::create_podcast`.
+mod store {
+    use async_trait::async_trait;
+    use axum::body::Bytes;
+    use std::path::{Path, PathBuf};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum StoreError {
+        #[error("no media found for key {0}")]
+        NotFound(String),
+        #[error("storage io error: {0}")]
+        Io(#[from] std::io::Error),
+        #[error("s3 error: {0}")]
+        S3(String),
+    }
+
+    #[async_trait]
+    pub trait MediaStore: Send + Sync {
+        /// Move the file currently at `src_path` into the store under `key`.
+        async fn put(&self, key: &str, src_path: &Path) -> Result<(), StoreError>;
+        async fn get(&self, key: &str) -> Result<Bytes, StoreError>;
+        async fn delete(&self, key: &str) -> Result<(), StoreError>;
+        async fn exists(&self, key: &str) -> Result<bool, StoreError>;
+    }
+
+    /// Stores blobs as plain files under `base_dir`, same as the original
+    /// hardcoded `./media/audio` behavior.
+    pub struct FileStore {
+        base_dir: PathBuf,
+    }
+
+    impl FileStore {
+        pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+            Self {
+                base_dir: base_dir.into(),
+            }
+        }
+
+        fn path_for(&self, key: &str) -> PathBuf {
+            self.base_dir.join(key)
+        }
+    }
+
+    #[async_trait]
+    impl MediaStore for FileStore {
+        async fn put(&self, key: &str, src_path: &Path) -> Result<(), StoreError> {
+            tokio::fs::rename(src_path, self.path_for(key)).await?;
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> Result<Bytes, StoreError> {
+            match tokio::fs::read(self.path_for(key)).await {
+                Ok(bytes) => Ok(Bytes::from(bytes)),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    Err(StoreError::NotFound(key.to_string()))
+                }
+                Err(err) => Err(StoreError::Io(err)),
+            }
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), StoreError> {
+            tokio::fs::remove_file(self.path_for(key)).await?;
+            Ok(())
+        }
+
+        async fn exists(&self, key: &str) -> Result<bool, StoreError> {
+            Ok(tokio::fs::try_exists(self.path_for(key)).await?)
+        }
+    }
+
+    /// Stores blobs in an S3-compatible bucket, selected via `BLOBSTORE_URI`.
+    pub struct S3Store {
+        client: aws_sdk_s3::Client,
+        bucket: String,
+    }
+
+    impl S3Store {
+        pub async fn new(bucket: impl Into<String>) -> Self {
+            let config = aws_config::load_from_env().await;
+            Self {
+                client: aws_sdk_s3::Client::new(&config),
+                bucket: bucket.into(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl MediaStore for S3Store {
+        async fn put(&self, key: &str, src_path: &Path) -> Result<(), StoreError> {
+            let body = aws_sdk_s3::primitives::ByteStream::from_path(src_path)
+                .await
+                .map_err(|err| StoreError::S3(err.to_string()))?;
+
+            self.client
+                .put_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .body(body)
+                .send()
+                .await
+                .map_err(|err| StoreError::S3(err.to_string()))?;
+
+            tokio::fs::remove_file(src_path).await?;
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> Result<Bytes, StoreError> {
+            let output = self
+                .client
+                .get_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .send()
+                .await
+                .map_err(|err| StoreError::S3(err.to_string()))?;
+
+            let data = output
+                .body
+                .collect()
+                .await
+                .map_err(|err| StoreError::S3(err.to_string()))?;
+
+            Ok(data.into_bytes())
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), StoreError> {
+            self.client
+                .delete_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .send()
+                .await
+                .map_err(|err| StoreError::S3(err.to_string()))?;
+            Ok(())
+        }
+
+        async fn exists(&self, key: &str) -> Result<bool, StoreError> {
+            match self
+                .client
+                .head_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .send()
+                .await
+            {
+                Ok(_) => Ok(true),
+                Err(err) => match err.as_service_error() {
+                    Some(service_err) if service_err.is_not_found() => Ok(false),
+                    _ => Err(StoreError::S3(err.to_string())),
+                },
+            }
+        }
+    }
+
+    /// Build the configured store from `BLOBSTORE_URI`, e.g.
+    /// `file://./media/audio` or `s3://my-bucket`. Defaults to a `FileStore`
+    /// rooted at `./media/audio` when unset.
+    pub async fn from_env() -> std::sync::Arc<dyn MediaStore> {
+        let uri =
+            std::env::var("BLOBSTORE_URI").unwrap_or_else(|_| "file://./media/audio".to_string());
+
+        if let Some(bucket) = uri.strip_prefix("s3://") {
+            std::sync::Arc::new(S3Store::new(bucket).await)
+        } else if let Some(path) = uri.strip_prefix("file://") {
+            std::sync::Arc::new(FileStore::new(path))
+        } else {
+            panic!("Unsupported BLOBSTORE_URI scheme: {uri}");
+        }
+    }
+}
+
+/// Extracts audio metadata (duration, bitrate, sample rate, codec) from a
+/// saved upload by shelling out to `ffprobe`, the same tool the rest of the
+/// ecosystem uses to introspect media files.
+mod probe {
+    use serde::Deserialize;
+    use std::path::Path;
+    use tokio::process::Command;
+
+    #[derive(Debug, Default)]
+    pub struct AudioMetadata {
+        pub duration_secs: Option<f64>,
+        pub bitrate: Option<i64>,
+        pub sample_rate: Option<i32>,
+        pub codec: Option<String>,
+        pub size_bytes: Option<i64>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct FfprobeOutput {
+        #[serde(default)]
+        streams: Vec<FfprobeStream>,
+        format: Option<FfprobeFormat>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct FfprobeStream {
+        codec_type: String,
+        codec_name: Option<String>,
+        #[serde(default)]
+        bit_rate: Option<String>,
+        #[serde(default)]
+        sample_rate: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct FfprobeFormat {
+        duration: Option<String>,
+        size: Option<String>,
+    }
+
+    /// Runs ffprobe against `path` and returns `None` if it has no audio
+    /// stream at all, meaning the upload isn't valid audio despite passing
+    /// the content-type check.
+    pub async fn probe(path: &Path) -> std::io::Result<Option<AudioMetadata>> {
+        let output = Command::new("ffprobe")
+            .args([
+                "-v",
+                "quiet",
+                "-print_format",
+                "json",
+                "-show_format",
+                "-show_streams",
+            ])
+            .arg(path)
+            .output()
+            .await?;
+
+        let parsed: FfprobeOutput = match serde_json::from_slice(&output.stdout) {
+            Ok(parsed) => parsed,
+            Err(_) => return Ok(None),
+        };
+
+        let Some(audio_stream) = parsed.streams.iter().find(|s| s.codec_type == "audio") else {
+            return Ok(None);
+        };
+
+        Ok(Some(AudioMetadata {
+            duration_secs: parsed
+                .format
+                .as_ref()
+                .and_then(|f| f.duration.as_ref())
+                .and_then(|d| d.parse().ok()),
+            bitrate: audio_stream.bit_rate.as_ref().and_then(|b| b.parse().ok()),
+            sample_rate: audio_stream
+                .sample_rate
+                .as_ref()
+                .and_then(|s| s.parse().ok()),
+            codec: audio_stream.codec_name.clone(),
+            size_bytes: parsed
+                .format
+                .as_ref()
+                .and_then(|f| f.size.as_ref())
+                .and_then(|s| s.parse().ok()),
+        }))
+    }
 }
 
 mod service {
-    use super::Podcast;
+    use super::store::MediaStore;
+    use super::{AppState, Podcast, DEFAULT_KEEP_FOR_DAYS};
     use axum::{
-        body::Bytes,
-        extract::{Multipart, Path, State},
+        extract::{Multipart, Path, Query, State},
         http::StatusCode,
         response::Json,
     };
+    use serde::{Deserialize, Serialize};
+    use sha2::{Digest, Sha256};
     use sqlx::PgPool;
+    use std::sync::Arc;
     use tokio::io::AsyncWriteExt;
     use uuid::Uuid;
 
-    pub async fn list_podcasts(State(pool): State<PgPool>) -> Json<Vec<Podcast>> {
-        let podcasts = sqlx::query_as!(Podcast, "SELECT * FROM podcast")
-            .fetch_all(&pool)
-            .await
-            .expect("Failed to fetch podcasts");
+    /// Default and maximum page size for [`list_podcasts`] when `?limit=`
+    /// isn't given or asks for more than we're willing to hand back.
+    const DEFAULT_LIMIT: i64 = 20;
+    const MAX_LIMIT: i64 = 100;
+
+    #[derive(Debug, Deserialize)]
+    pub struct ListPodcastsQuery {
+        q: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    }
 
-        Json(podcasts)
+    #[derive(Debug, Serialize)]
+    pub struct PaginatedPodcasts {
+        items: Vec<Podcast>,
+        total: i64,
+        limit: i64,
+        offset: i64,
+    }
+
+    /// Lists podcasts, optionally filtered by `q` and always paginated via
+    /// `limit`/`offset`. `q` runs a Postgres full-text search over the
+    /// title, falling back to a plain `ILIKE` for queries too short for
+    /// `plainto_tsquery` to match meaningfully.
+    pub async fn list_podcasts(
+        State(pool): State<PgPool>,
+        Query(params): Query<ListPodcastsQuery>,
+    ) -> Result<Json<PaginatedPodcasts>, StatusCode> {
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let offset = params.offset.unwrap_or(0).max(0);
+
+        let query = params.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+
+        let (items, total) = match query {
+            Some(q) if q.chars().count() >= 3 => {
+                let items = sqlx::query_as!(
+                    Podcast,
+                    "SELECT * FROM podcast
+                     WHERE to_tsvector('english', title) @@ plainto_tsquery('english', $1)
+                     ORDER BY created_at DESC
+                     LIMIT $2 OFFSET $3",
+                    q,
+                    limit,
+                    offset
+                )
+                .fetch_all(&pool)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+                let total = sqlx::query_scalar!(
+                    "SELECT COUNT(*) FROM podcast
+                     WHERE to_tsvector('english', title) @@ plainto_tsquery('english', $1)",
+                    q
+                )
+                .fetch_one(&pool)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .unwrap_or(0);
+
+                (items, total)
+            }
+            Some(q) => {
+                let pattern = format!("%{}%", q);
+
+                let items = sqlx::query_as!(
+                    Podcast,
+                    "SELECT * FROM podcast WHERE title ILIKE $1 ORDER BY created_at DESC LIMIT $2 OFFSET $3",
+                    pattern,
+                    limit,
+                    offset
+                )
+                .fetch_all(&pool)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+                let total = sqlx::query_scalar!(
+                    "SELECT COUNT(*) FROM podcast WHERE title ILIKE $1",
+                    pattern
+                )
+                .fetch_one(&pool)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .unwrap_or(0);
+
+                (items, total)
+            }
+            None => {
+                let items = sqlx::query_as!(
+                    Podcast,
+                    "SELECT * FROM podcast ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+                    limit,
+                    offset
+                )
+                .fetch_all(&pool)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+                let total = sqlx::query_scalar!("SELECT COUNT(*) FROM podcast")
+                    .fetch_one(&pool)
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                    .unwrap_or(0);
+
+                (items, total)
+            }
+        };
+
+        Ok(Json(PaginatedPodcasts {
+            items,
+            total,
+            limit,
+            offset,
+        }))
+    }
+
+    /// Best-effort cleanup for an aborted upload: removes the `.tmp-*` file
+    /// still being written to, and/or a blob this request already committed
+    /// to the store before some later field or the DB insert failed. Without
+    /// this, those failure paths leak disk or an orphaned, never-referenced
+    /// blob.
+    async fn cleanup_partial_upload(
+        tmp_path: Option<&str>,
+        committed_key: Option<&str>,
+        store: &Arc<dyn MediaStore>,
+    ) {
+        if let Some(tmp_path) = tmp_path {
+            let _ = tokio::fs::remove_file(tmp_path).await;
+        }
+        if let Some(key) = committed_key {
+            if let Err(err) = store.delete(key).await {
+                tracing::error!("Failed to remove orphaned upload blob {key}: {err}");
+            }
+        }
     }
 
     /// Create a new podcast
     /// This function expects a multipart request with the following fields:
     /// - title: String
     /// - file: File
-    /// The file field should be a valid audio file.
+    /// - keep_for: optional number of days to keep the episode before it's
+    ///   auto-deleted, or "permanent" to keep it forever. Defaults to
+    ///   `DEFAULT_KEEP_FOR_DAYS`.
+    /// - folder_id: optional UUID of the show this episode belongs to.
+    /// The file field must be a valid audio file; this is checked both by
+    /// content-type and by running it through ffprobe, which also supplies
+    /// the duration/bitrate/sample-rate/codec stored alongside it.
     /// The function will save the file to the disk and store the metadata in the database.
     pub async fn create_podcast(
-        State(pool): State<PgPool>,
+        State(state): State<AppState>,
         mut payload: Multipart,
     ) -> Result<Json<Option<Podcast>>, StatusCode> {
+        let pool = state.pool;
         let mut title = None;
         let mut url = None;
+        let mut keep_for = None;
+        let mut folder_id = None;
+        let mut audio_content_type = None;
+        let mut metadata = super::probe::AudioMetadata::default();
 
-        let mut file: Option<tokio::fs::File> = None;
-        let mut bytes: Option<Bytes> = None;
+        // Tracks the temp file currently being written and any blob this
+        // request has already put into the store, so an error on a later
+        // field or the final DB insert can clean both up instead of leaking
+        // them.
+        let mut tmp_path: Option<String> = None;
+        let mut committed_key: Option<String> = None;
 
-        while let Some(field) = payload.next_field().await.unwrap() {
+        while let Some(mut field) = payload.next_field().await.unwrap() {
             let name = field.name().unwrap();
             match name {
                 "title" => {
                     title = Some(field.text().await.unwrap());
                 }
+                "keep_for" => {
+                    keep_for = Some(field.text().await.unwrap());
+                }
+                "folder_id" => {
+                    let text = field.text().await.unwrap();
+                    match text.parse::<Uuid>() {
+                        Ok(id) => folder_id = Some(id),
+                        Err(_) => {
+                            cleanup_partial_upload(
+                                tmp_path.as_deref(),
+                                committed_key.as_deref(),
+                                &state.store,
+                            )
+                            .await;
+                            return Err(StatusCode::BAD_REQUEST);
+                        }
+                    }
+                }
                 "file" => {
                     let content_type = field.content_type().unwrap().to_string();
                     let audio_content_types = vec![
@@ -78,28 +566,123 @@ mod service {
                     ];
 
                     if !audio_content_types.contains(&content_type.as_str()) {
+                        cleanup_partial_upload(
+                            tmp_path.as_deref(),
+                            committed_key.as_deref(),
+                            &state.store,
+                        )
+                        .await;
                         return Err(StatusCode::BAD_REQUEST);
                     }
 
-                    let file_name = format!(
-                        "{}.{}",
-                        Uuid::new_v4(),
-                        content_type.split("/").last().unwrap()
-                    );
+                    let extension = content_type.split("/").last().unwrap();
 
-                    let file_path = format!("./media/audio/{}", file_name);
-                    file = Some(
-                        tokio::fs::File::create(&file_path)
-                            .await
-                            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
-                    );
+                    // Stream the chunks to a temporary file while hashing them,
+                    // so neither the whole upload nor the final digest needs to
+                    // be known up front.
+                    let this_tmp_path = format!("./media/audio/.tmp-{}", Uuid::new_v4());
+                    let mut tmp_file = match tokio::fs::File::create(&this_tmp_path).await {
+                        Ok(file) => file,
+                        Err(_) => return Err(StatusCode::INTERNAL_SERVER_ERROR),
+                    };
+                    tmp_path = Some(this_tmp_path.clone());
+
+                    let mut hasher = Sha256::new();
+                    loop {
+                        match field.chunk().await {
+                            Ok(Some(chunk)) => {
+                                hasher.update(&chunk);
+                                if tmp_file.write_all(&chunk).await.is_err() {
+                                    cleanup_partial_upload(
+                                        tmp_path.as_deref(),
+                                        committed_key.as_deref(),
+                                        &state.store,
+                                    )
+                                    .await;
+                                    return Err(StatusCode::INTERNAL_SERVER_ERROR);
+                                }
+                            }
+                            Ok(None) => break,
+                            Err(_) => {
+                                cleanup_partial_upload(
+                                    tmp_path.as_deref(),
+                                    committed_key.as_deref(),
+                                    &state.store,
+                                )
+                                .await;
+                                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+                            }
+                        }
+                    }
+
+                    let probed =
+                        match super::probe::probe(std::path::Path::new(&this_tmp_path)).await {
+                            Ok(probed) => probed,
+                            Err(_) => {
+                                cleanup_partial_upload(
+                                    tmp_path.as_deref(),
+                                    committed_key.as_deref(),
+                                    &state.store,
+                                )
+                                .await;
+                                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+                            }
+                        };
+
+                    let Some(probed) = probed else {
+                        cleanup_partial_upload(
+                            tmp_path.as_deref(),
+                            committed_key.as_deref(),
+                            &state.store,
+                        )
+                        .await;
+                        return Err(StatusCode::BAD_REQUEST);
+                    };
+                    metadata = probed;
+                    audio_content_type = Some(content_type.clone());
 
-                    bytes = Some(
-                        field
-                            .bytes()
+                    let digest = format!("{:x}", hasher.finalize());
+                    let file_name = format!("{}.{}", digest, extension);
+
+                    // Content-addressed storage: if a file with this digest
+                    // already exists in the store, drop the temp file and
+                    // just point the new row at the existing blob.
+                    let exists = match state.store.exists(&file_name).await {
+                        Ok(exists) => exists,
+                        Err(_) => {
+                            cleanup_partial_upload(
+                                tmp_path.as_deref(),
+                                committed_key.as_deref(),
+                                &state.store,
+                            )
+                            .await;
+                            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+                        }
+                    };
+
+                    if exists {
+                        let _ = tokio::fs::remove_file(&this_tmp_path).await;
+                    } else {
+                        if let Err(_) = state
+                            .store
+                            .put(&file_name, std::path::Path::new(&this_tmp_path))
                             .await
-                            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
-                    );
+                        {
+                            cleanup_partial_upload(
+                                tmp_path.as_deref(),
+                                committed_key.as_deref(),
+                                &state.store,
+                            )
+                            .await;
+                            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+                        }
+                        // This request now owns the blob; if anything later
+                        // fails it must be cleaned up rather than orphaned.
+                        committed_key = Some(file_name.clone());
+                    }
+                    // The temp file was either removed (dedup) or moved into
+                    // the store (put), so there's nothing left at this path.
+                    tmp_path = None;
 
                     url = Some(format!("/audio/{}", file_name));
                 }
@@ -110,23 +693,51 @@ mod service {
         let title = title.unwrap();
         let url = url.unwrap();
 
-        if let Some(mut file) = file {
-            if let Some(bytes) = bytes {
-                file.write_all(&bytes)
-                    .await
-                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let valid_till = match keep_for.as_deref() {
+            Some("permanent") => None,
+            Some(days) => {
+                let days: i64 = match days.parse() {
+                    Ok(days) => days,
+                    Err(_) => {
+                        cleanup_partial_upload(None, committed_key.as_deref(), &state.store).await;
+                        return Err(StatusCode::BAD_REQUEST);
+                    }
+                };
+                Some(chrono::Utc::now().naive_utc() + chrono::Duration::days(days))
             }
-        }
+            None => {
+                Some(chrono::Utc::now().naive_utc() + chrono::Duration::days(DEFAULT_KEEP_FOR_DAYS))
+            }
+        };
 
-        let podcast = sqlx::query_as!(
+        let podcast = match sqlx::query_as!(
             Podcast,
-            "INSERT INTO podcast (title, url) VALUES ($1, $2) RETURNING *",
+            "INSERT INTO podcast (title, url, valid_till, duration_secs, bitrate, sample_rate, codec, content_type, folder_id, size_bytes)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
             title,
-            url
+            url,
+            valid_till,
+            metadata.duration_secs,
+            metadata.bitrate,
+            metadata.sample_rate,
+            metadata.codec,
+            audio_content_type,
+            folder_id,
+            metadata.size_bytes,
         )
         .fetch_one(&pool)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        {
+            Ok(podcast) => podcast,
+            Err(_) => {
+                cleanup_partial_upload(None, committed_key.as_deref(), &state.store).await;
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        };
+
+        // Wake the expiry deleter in case this upload's `valid_till` is
+        // sooner than whatever it's currently sleeping on.
+        let _ = state.expiry_notify.send(()).await;
 
         Ok(Json(Some(podcast)))
     }
@@ -146,49 +757,394 @@ mod service {
     pub async fn delete_podcast(
         Path(id): Path<Uuid>,
         State(pool): State<PgPool>,
+        State(store): State<Arc<dyn MediaStore>>,
     ) -> Result<StatusCode, StatusCode> {
-        // Delete the file from the disk
         let url: String = sqlx::query_scalar!("SELECT url FROM podcast WHERE id = $1", id)
             .fetch_one(&pool)
             .await
             .map_err(|_| StatusCode::NOT_FOUND)?;
 
-        let file_path = format!("./media{}", url);
-        tokio::fs::remove_file(&file_path)
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
         sqlx::query!("DELETE FROM podcast WHERE id = $1", id)
             .execute(&pool)
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+        // Content-addressed uploads can share a stored blob across rows, so
+        // only remove it once nothing else points at it.
+        if !super::url_still_referenced(&pool, &url)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        {
+            store
+                .delete(super::key_from_url(&url))
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        }
+
         Ok(StatusCode::NO_CONTENT)
     }
 
-    pub async fn delete_all_podcasts(State(pool): State<PgPool>) -> Result<StatusCode, StatusCode> {
-        // Delete all files from the disk
+    pub async fn get_audio(
+        Path(key): Path<String>,
+        State(store): State<Arc<dyn MediaStore>>,
+    ) -> Result<axum::body::Bytes, StatusCode> {
+        store.get(&key).await.map_err(|err| match err {
+            super::store::StoreError::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })
+    }
+
+    pub async fn delete_all_podcasts(
+        State(pool): State<PgPool>,
+        State(store): State<Arc<dyn MediaStore>>,
+    ) -> Result<StatusCode, StatusCode> {
         let urls: Vec<String> = sqlx::query_scalar!("SELECT url FROM podcast")
             .fetch_all(&pool)
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-        for url in urls {
-            let file_path = format!("./media{}", url);
-            tokio::fs::remove_file(&file_path)
+        sqlx::query!("DELETE FROM podcast")
+            .execute(&pool)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        // Every row is gone, so every referenced blob can go too. Dedup
+        // first since content-addressed uploads can share a url.
+        let unique_urls: std::collections::HashSet<String> = urls.into_iter().collect();
+        for url in unique_urls {
+            store
+                .delete(super::key_from_url(&url))
                 .await
                 .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
         }
 
-        sqlx::query!("DELETE FROM podcast")
-            .execute(&pool)
+        Ok(StatusCode::NO_CONTENT)
+    }
+}
+
+/// Shows/folders: a nestable way to organize episodes.
+mod folders {
+    use super::store::MediaStore;
+    use super::{Folder, Podcast};
+    use axum::{
+        extract::{Path, State},
+        http::StatusCode,
+        Json,
+    };
+    use serde::Deserialize;
+    use sqlx::PgPool;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    #[derive(Debug, Deserialize)]
+    pub struct CreateFolder {
+        name: String,
+        parent_folder_id: Option<Uuid>,
+    }
+
+    pub async fn create_folder(
+        State(pool): State<PgPool>,
+        Json(body): Json<CreateFolder>,
+    ) -> Result<Json<Folder>, StatusCode> {
+        let folder = sqlx::query_as!(
+            Folder,
+            "INSERT INTO folder (name, parent_folder_id) VALUES ($1, $2) RETURNING *",
+            body.name,
+            body.parent_folder_id
+        )
+        .fetch_one(&pool)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        Ok(Json(folder))
+    }
+
+    pub async fn list_folders(
+        State(pool): State<PgPool>,
+    ) -> Result<Json<Vec<Folder>>, StatusCode> {
+        let folders = sqlx::query_as!(Folder, "SELECT * FROM folder")
+            .fetch_all(&pool)
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+        Ok(Json(folders))
+    }
+
+    pub async fn folder_episodes(
+        Path(id): Path<Uuid>,
+        State(pool): State<PgPool>,
+    ) -> Result<Json<Vec<Podcast>>, StatusCode> {
+        let podcasts = sqlx::query_as!(Podcast, "SELECT * FROM podcast WHERE folder_id = $1", id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        Ok(Json(podcasts))
+    }
+
+    /// Deletes a folder, every descendant folder, and every episode they
+    /// contain, using a single recursive CTE so the whole cascade -
+    /// including the list of files to remove from the store - happens in
+    /// one round trip to Postgres.
+    pub async fn delete_folder(
+        Path(id): Path<Uuid>,
+        State(pool): State<PgPool>,
+        State(store): State<Arc<dyn MediaStore>>,
+    ) -> Result<StatusCode, StatusCode> {
+        let deleted_files = sqlx::query!(
+            r#"
+            WITH RECURSIVE folder_hierarchy AS (
+                SELECT id FROM folder WHERE id = $1
+                UNION ALL
+                SELECT f.id FROM folder f
+                JOIN folder_hierarchy fh ON f.parent_folder_id = fh.id
+            ),
+            deleted_podcasts AS (
+                DELETE FROM podcast
+                WHERE folder_id IN (SELECT id FROM folder_hierarchy)
+                RETURNING id, url
+            ),
+            deleted_folders AS (
+                DELETE FROM folder
+                WHERE id IN (SELECT id FROM folder_hierarchy)
+                RETURNING id
+            )
+            SELECT id, url FROM deleted_podcasts
+            "#,
+            id
+        )
+        .fetch_all(&pool)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        // Content-addressed uploads can share a stored blob across rows
+        // (including ones outside this folder's subtree), so dedup the
+        // returned urls and only remove each blob once nothing references
+        // it anymore.
+        let unique_urls: std::collections::HashSet<String> =
+            deleted_files.into_iter().map(|file| file.url).collect();
+
+        for url in unique_urls {
+            match super::url_still_referenced(&pool, &url).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    if let Err(err) = store.delete(super::key_from_url(&url)).await {
+                        tracing::error!("Failed to remove file for deleted folder contents: {err}");
+                    }
+                }
+                Err(err) => {
+                    tracing::error!("Failed to check remaining references for {url}: {err}");
+                }
+            }
+        }
+
         Ok(StatusCode::NO_CONTENT)
     }
 }
 
+/// Renders the podcast RSS 2.0 feeds, one for the whole library and one per
+/// folder, so any podcast client can subscribe instead of polling the JSON
+/// API.
+mod feed {
+    use super::{Folder, Podcast};
+    use axum::{
+        extract::{Path, State},
+        http::{header, HeaderMap, StatusCode},
+        response::{IntoResponse, Response},
+    };
+    use rss::extension::itunes::ITunesItemExtensionBuilder;
+    use rss::{ChannelBuilder, EnclosureBuilder, ItemBuilder};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    /// Builds an absolute base URL (e.g. `http://podcasts.example.com`) from
+    /// the request's `Host` header so enclosure URLs work behind any domain.
+    fn host_url(headers: &HeaderMap) -> String {
+        let host = headers
+            .get(header::HOST)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("localhost:3000");
+        format!("http://{host}")
+    }
+
+    fn render(title: &str, host: &str, podcasts: Vec<Podcast>) -> String {
+        let items = podcasts
+            .into_iter()
+            .map(|podcast| {
+                let enclosure = EnclosureBuilder::default()
+                    .url(format!("{host}{}", podcast.url))
+                    .mime_type(
+                        podcast
+                            .content_type
+                            .clone()
+                            .unwrap_or_else(|| "audio/mpeg".to_string()),
+                    )
+                    .length(podcast.size_bytes.unwrap_or(0).to_string())
+                    .build();
+
+                let itunes_ext = ITunesItemExtensionBuilder::default()
+                    .duration(podcast.duration_secs.map(|secs| (secs as i64).to_string()))
+                    .build();
+
+                ItemBuilder::default()
+                    .title(Some(podcast.title))
+                    .enclosure(Some(enclosure))
+                    .pub_date(
+                        podcast
+                            .created_at
+                            .map(|created_at| created_at.and_utc().to_rfc2822()),
+                    )
+                    .itunes_ext(Some(itunes_ext))
+                    .build()
+            })
+            .collect::<Vec<_>>();
+
+        ChannelBuilder::default()
+            .title(title)
+            .link(host)
+            .description(format!("{title} episodes"))
+            .items(items)
+            .build()
+            .to_string()
+    }
+
+    fn xml_response(body: String) -> Response {
+        (
+            [(header::CONTENT_TYPE, "application/rss+xml; charset=utf-8")],
+            body,
+        )
+            .into_response()
+    }
+
+    pub async fn podcasts_feed(
+        headers: HeaderMap,
+        State(pool): State<PgPool>,
+    ) -> Result<Response, StatusCode> {
+        let podcasts = sqlx::query_as!(Podcast, "SELECT * FROM podcast ORDER BY created_at DESC")
+            .fetch_all(&pool)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        Ok(xml_response(render("Podcasts", &host_url(&headers), podcasts)))
+    }
+
+    pub async fn folder_feed(
+        Path(id): Path<Uuid>,
+        headers: HeaderMap,
+        State(pool): State<PgPool>,
+    ) -> Result<Response, StatusCode> {
+        let folder = sqlx::query_as!(Folder, "SELECT * FROM folder WHERE id = $1", id)
+            .fetch_one(&pool)
+            .await
+            .map_err(|_| StatusCode::NOT_FOUND)?;
+
+        let podcasts = sqlx::query_as!(
+            Podcast,
+            "SELECT * FROM podcast WHERE folder_id = $1 ORDER BY created_at DESC",
+            id
+        )
+        .fetch_all(&pool)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        Ok(xml_response(render(&folder.name, &host_url(&headers), podcasts)))
+    }
+}
+
+/// Background task that deletes expired podcasts: sleep until the soonest
+/// `valid_till`, wake up and sweep whatever's due, then recompute the next
+/// deadline. The upload handler notifies `rx` so a newly-uploaded file
+/// expiring sooner than the current sleep gets picked up immediately
+/// instead of waiting out the old deadline.
+mod expiry {
+    use super::store::MediaStore;
+    use sqlx::PgPool;
+    use std::sync::Arc;
+    use tokio::sync::mpsc;
+
+    pub async fn run_deleter(pool: PgPool, store: Arc<dyn MediaStore>, mut rx: mpsc::Receiver<()>) {
+        loop {
+            let next_deadline =
+                sqlx::query_scalar!("SELECT MIN(valid_till) FROM podcast WHERE valid_till IS NOT NULL")
+                    .fetch_one(&pool)
+                    .await
+                    .ok()
+                    .flatten();
+
+            match next_deadline {
+                Some(deadline) => {
+                    let now = chrono::Utc::now().naive_utc();
+                    let sleep_for = (deadline - now).to_std().unwrap_or(std::time::Duration::ZERO);
+
+                    tokio::select! {
+                        _ = tokio::time::sleep(sleep_for) => {
+                            sweep_expired(&pool, &store).await;
+                        }
+                        notified = rx.recv() => {
+                            if notified.is_none() {
+                                return;
+                            }
+                            // An upload landed and may expire sooner; loop around
+                            // to recompute the deadline instead of sleeping it out.
+                        }
+                    }
+                }
+                None => {
+                    // Nothing scheduled to expire; wait for an upload to tell us
+                    // it added a deadline.
+                    if rx.recv().await.is_none() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn sweep_expired(pool: &PgPool, store: &Arc<dyn MediaStore>) {
+        let now = chrono::Utc::now().naive_utc();
+        let expired = match sqlx::query!(
+            "SELECT id, url FROM podcast WHERE valid_till IS NOT NULL AND valid_till <= $1",
+            now
+        )
+        .fetch_all(pool)
+        .await
+        {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed to query expired podcasts: {err}");
+                return;
+            }
+        };
+
+        for row in expired {
+            if let Err(err) = sqlx::query!("DELETE FROM podcast WHERE id = $1", row.id)
+                .execute(pool)
+                .await
+            {
+                tracing::error!("Failed to delete expired podcast {}: {err}", row.id);
+                continue;
+            }
+
+            // Content-addressed uploads can share a stored blob across
+            // rows, so only remove it once nothing else points at it.
+            match super::url_still_referenced(pool, &row.url).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    if let Err(err) = store.delete(super::key_from_url(&row.url)).await {
+                        tracing::error!("Failed to remove expired file {}: {err}", row.url);
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(
+                        "Failed to check remaining references for {}: {err}",
+                        row.url
+                    );
+                }
+            }
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() {
     tracing_subscriber::registry()
@@ -219,6 +1175,21 @@ async fn main() {
         .await
         .expect("Failed to create media directory");
 
+    let media_store = store::from_env().await;
+
+    let (expiry_notify, expiry_rx) = mpsc::channel(1);
+    tokio::spawn(expiry::run_deleter(
+        pool.clone(),
+        media_store.clone(),
+        expiry_rx,
+    ));
+
+    let state = AppState {
+        pool,
+        expiry_notify,
+        store: media_store,
+    };
+
     let app_router = Router::new()
         .nest_service("/assets", ServeDir::new("./static/dist/assets"))
         .route_service("/", ServeFile::new("./static/dist/index.html"))
@@ -232,6 +1203,17 @@ async fn main() {
             get(service::get_podcast).delete(service::delete_podcast),
         )
         .route("/podcasts", delete(service::delete_all_podcasts))
+        .route("/podcasts/feed.xml", get(feed::podcasts_feed))
+        .route("/audio/:key", get(service::get_audio))
+        .route(
+            "/folders",
+            get(folders::list_folders).post(folders::create_folder),
+        )
+        .route(
+            "/folders/:id",
+            get(folders::folder_episodes).delete(folders::delete_folder),
+        )
+        .route("/folders/:id/feed.xml", get(feed::folder_feed))
         .fallback(not_found)
         .layer(DefaultBodyLimit::disable())
         .layer(RequestBodyLimitLayer::new(
@@ -242,7 +1224,7 @@ async fn main() {
                 .allow_origin("http://0.0.0.0:3000".parse::<HeaderValue>().unwrap())
                 .allow_methods(vec![Method::GET, Method::POST, Method::DELETE]),
         )
-        .with_state(pool);
+        .with_state(state);
 
     let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();